@@ -243,7 +243,7 @@ NTSTATUS MConf_Write(_In_ VMM_HANDLE H, _In_ PVMMDLL_PLUGIN_CONTEXT ctxP, _In_re
     if(!_strnicmp(ctxP->uszPath, "config_symbol.txt", 13)) {
         nt = VMMDLL_STATUS_FILE_INVALID;
         if(!_stricmp(ctxP->uszPath, "config_symbol_enable.txt")) {
-            nt = Util_VfsWriteFile_DWORD(&H->pdb.fEnable, pb, cb, pcbWrite, cbOffset, 1, 0);
+            nt = Util_VfsWriteFile_DWORD(&H->pdb.fEnable, pb, cb, pcbWrite, cbOffset, 0, 1);
         }
         if(!_stricmp(ctxP->uszPath, "config_symbolcache.txt")) {
             nt = Util_VfsWriteFile_PBYTE(H->pdb.szLocal, _countof(H->pdb.szLocal) - 1, pb, cb, pcbWrite, cbOffset, TRUE);
@@ -252,7 +252,7 @@ NTSTATUS MConf_Write(_In_ VMM_HANDLE H, _In_ PVMMDLL_PLUGIN_CONTEXT ctxP, _In_re
             nt = Util_VfsWriteFile_PBYTE(H->pdb.szServer, _countof(H->pdb.szServer) - 1, pb, cb, pcbWrite, cbOffset, TRUE);
         }
         if(!_stricmp(ctxP->uszPath, "config_symbolserver_enable.txt")) {
-            nt = Util_VfsWriteFile_DWORD(&H->pdb.fServerEnable, pb, cb, pcbWrite, cbOffset, 1, 0);
+            nt = Util_VfsWriteFile_DWORD(&H->pdb.fServerEnable, pb, cb, pcbWrite, cbOffset, 0, 1);
         }
         PDB_ConfigChange(H);
         return nt;
@@ -61,6 +61,7 @@ typedef enum tdSTATISTICS_ID {
     STATISTICS_ID_VMMDLL_Map_GetUsers,
     STATISTICS_ID_VMMDLL_Map_GetVM,
     STATISTICS_ID_VMMDLL_Map_GetServices,
+    STATISTICS_ID_VMMDLL_Map_GetKDriver,
     STATISTICS_ID_VMMDLL_Map_GetPfn,
     STATISTICS_ID_VMMDLL_Map_GetPfnEx,
     STATISTICS_ID_VMMDLL_ProcessGetDirectories,
@@ -144,6 +145,7 @@ static LPCSTR STATISTICS_ID_STR[STATISTICS_ID_MAX] = {
     [STATISTICS_ID_VMMDLL_Map_GetUsers]             = "VMMDLL_Map_GetUsers",
     [STATISTICS_ID_VMMDLL_Map_GetVM]                = "MMDLL_Map_GetVM",
     [STATISTICS_ID_VMMDLL_Map_GetServices]          = "VMMDLL_Map_GetServices",
+    [STATISTICS_ID_VMMDLL_Map_GetKDriver]           = "VMMDLL_Map_GetKDriver",
     [STATISTICS_ID_VMMDLL_Map_GetPfn]               = "VMMDLL_Map_GetPfn",
     [STATISTICS_ID_VMMDLL_Map_GetPfnEx]             = "VMMDLL_Map_GetPfnEx",
     [STATISTICS_ID_VMMDLL_ProcessGetDirectories]    = "VMMDLL_ProcessGetDirectories",
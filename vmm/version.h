@@ -4,7 +4,7 @@
 #define VERSION_MAJOR               5
 #define VERSION_MINOR               4
 #define VERSION_REVISION            4
-#define VERSION_BUILD               105
+#define VERSION_BUILD               106
 
 #define VER_FILE_DESCRIPTION_STR    "MemProcFS : Core"
 #define VER_FILE_VERSION            VERSION_MAJOR, VERSION_MINOR, VERSION_REVISION, VERSION_BUILD
@@ -29,6 +29,7 @@
 #define OB_TAG_API_MAP_HEAP             'HEAP'
 #define OB_TAG_API_MAP_HEAP_ALLOC       'HEPA'
 #define OB_TAG_API_MAP_IAT              'IAT '
+#define OB_TAG_API_MAP_KDRIVER          'KDRV'
 #define OB_TAG_API_MAP_MODULE           'MOD '
 #define OB_TAG_API_MAP_NET              'NET '
 #define OB_TAG_API_MAP_PHYSMEM          'PMEM'
@@ -1873,6 +1874,66 @@ _Success_(return) BOOL VMMDLL_Map_GetServicesW(_In_ VMM_HANDLE H, _Out_ PVMMDLL_
     CALL_IMPLEMENTATION_VMM(H, STATISTICS_ID_VMMDLL_Map_GetServices, VMMDLL_Map_GetServices_Impl(H, ppServiceMap, TRUE))
 }
 
+_Success_(return)
+BOOL VMMDLL_Map_GetKDriver_Impl(_In_ VMM_HANDLE H, _Out_ PVMMDLL_MAP_KDRIVER *ppMapDst, _In_ BOOL fWideChar)
+{
+    BOOL f;
+    DWORD i, cbDst = 0, cbDstData, cbDstStr;
+    PVMMDLL_MAP_KDRIVERENTRY peDst;
+    PVMM_MAP_KDRIVERENTRY peSrc;
+    PVMMOB_MAP_KDRIVER pObMapSrc = NULL;
+    PVMMDLL_MAP_KDRIVER pMapDst = NULL;
+    POB_STRMAP psmOb = NULL;
+    *ppMapDst = NULL;
+    // 0: sanity check:
+    if(sizeof(VMM_MAP_KDRIVERENTRY) != sizeof(VMMDLL_MAP_KDRIVERENTRY)) { goto fail; }
+    // 1: fetch map [and populate strings]:
+    if(!(psmOb = ObStrMap_New(H, 0))) { goto fail; }
+    if(!VmmMap_GetKDriver(H, &pObMapSrc)) { goto fail; }
+    for(i = 0; i < pObMapSrc->cMap; i++) {
+        peSrc = pObMapSrc->pMap + i;
+        ObStrMap_PushU(psmOb, peSrc->uszName);
+        ObStrMap_PushU(psmOb, peSrc->uszPath);
+        ObStrMap_PushU(psmOb, peSrc->uszServiceKeyName);
+    }
+    // 2: byte count & alloc:
+    if(!ObStrMap_FinalizeBufferXUW(psmOb, 0, NULL, &cbDstStr, fWideChar)) { goto fail; }
+    cbDstData = pObMapSrc->cMap * sizeof(VMMDLL_MAP_KDRIVERENTRY);
+    cbDst = sizeof(VMMDLL_MAP_KDRIVER) + cbDstData + cbDstStr;
+    if(!(pMapDst = VmmDllCore_MemAllocExternal(H, OB_TAG_API_MAP_KDRIVER, cbDst, sizeof(VMMDLL_MAP_KDRIVER)))) { goto fail; }    // VMMDLL_MemFree()
+    // 3: fill map:
+    pMapDst->dwVersion = VMMDLL_MAP_KDRIVER_VERSION;
+    pMapDst->cMap = pObMapSrc->cMap;
+    memcpy(pMapDst->pMap, pObMapSrc->pMap, cbDstData);
+    // strmap below:
+    for(i = 0; i < pMapDst->cMap; i++) {
+        peSrc = pObMapSrc->pMap + i;
+        peDst = pMapDst->pMap + i;
+        f = ObStrMap_PushPtrUXUW(psmOb, peSrc->uszName, &peDst->uszName, NULL, fWideChar) &&
+            ObStrMap_PushPtrUXUW(psmOb, peSrc->uszPath, &peDst->uszPath, NULL, fWideChar) &&
+            ObStrMap_PushPtrUXUW(psmOb, peSrc->uszServiceKeyName, &peDst->uszServiceKeyName, NULL, fWideChar);
+        if(!f) { goto fail; }
+    }
+    pMapDst->pbMultiText = ((PBYTE)pMapDst->pMap) + cbDstData;
+    ObStrMap_FinalizeBufferXUW(psmOb, cbDstStr, pMapDst->pbMultiText, &pMapDst->cbMultiText, fWideChar);
+    *ppMapDst = pMapDst;
+fail:
+    if(pMapDst && !*ppMapDst) { VMMDLL_MemFree(pMapDst); pMapDst = NULL; }
+    Ob_DECREF(pObMapSrc);
+    Ob_DECREF(psmOb);
+    return *ppMapDst ? TRUE : FALSE;
+}
+
+_Success_(return) BOOL VMMDLL_Map_GetKDriverU(_In_ VMM_HANDLE H, _Out_ PVMMDLL_MAP_KDRIVER *ppKDriverMap)
+{
+    CALL_IMPLEMENTATION_VMM(H, STATISTICS_ID_VMMDLL_Map_GetKDriver, VMMDLL_Map_GetKDriver_Impl(H, ppKDriverMap, FALSE))
+}
+
+_Success_(return) BOOL VMMDLL_Map_GetKDriverW(_In_ VMM_HANDLE H, _Out_ PVMMDLL_MAP_KDRIVER *ppKDriverMap)
+{
+    CALL_IMPLEMENTATION_VMM(H, STATISTICS_ID_VMMDLL_Map_GetKDriver, VMMDLL_Map_GetKDriver_Impl(H, ppKDriverMap, TRUE))
+}
+
 _Success_(return)
 BOOL VMMDLL_Map_GetPfn_Impl(_In_ VMM_HANDLE H, _In_reads_(cPfns) DWORD pPfns[], _In_ DWORD cPfns, _Out_writes_bytes_opt_(*pcbMapDst) PVMMDLL_MAP_PFN pMapDst, _Inout_ PDWORD pcbMapDst)
 {
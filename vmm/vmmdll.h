@@ -976,6 +976,7 @@ VOID VMMDLL_Scatter_CloseHandle(_In_opt_ _Post_ptr_invalid_ VMMDLL_SCATTER_HANDL
 #define VMMDLL_MAP_USER_VERSION             2
 #define VMMDLL_MAP_VM_VERSION               2
 #define VMMDLL_MAP_SERVICE_VERSION          3
+#define VMMDLL_MAP_KDRIVER_VERSION          1
 
 // flags to check for existence in the fPage field of VMMDLL_MAP_PTEENTRY
 #define VMMDLL_MEMMAP_FLAG_PAGE_W           0x0000000000000002
@@ -1363,6 +1364,19 @@ typedef struct tdVMMDLL_MAP_SERVICEENTRY {
     QWORD _FutureUse2;
 } VMMDLL_MAP_SERVICEENTRY, *PVMMDLL_MAP_SERVICEENTRY;
 
+typedef struct tdVMMDLL_MAP_KDRIVERENTRY {
+    QWORD va;
+    DWORD dwHash;
+    DWORD _Reserved;
+    QWORD vaStart;
+    QWORD cbDriverSize;
+    QWORD vaDeviceObject;
+    union { LPSTR  uszName;           LPWSTR wszName;           QWORD _Reserved1; };  // U/W dependant
+    union { LPSTR  uszPath;           LPWSTR wszPath;           QWORD _Reserved2; };  // U/W dependant
+    union { LPSTR  uszServiceKeyName; LPWSTR wszServiceKeyName; QWORD _Reserved3; };  // U/W dependant
+    QWORD MajorFunction[28];
+} VMMDLL_MAP_KDRIVERENTRY, *PVMMDLL_MAP_KDRIVERENTRY;
+
 typedef struct tdVMMDLL_MAP_PTE {
     DWORD dwVersion;
     DWORD _Reserved1[5];
@@ -1521,6 +1535,15 @@ typedef struct tdVMMDLL_MAP_SERVICE {
     VMMDLL_MAP_SERVICEENTRY pMap[]; // map entries.
 } VMMDLL_MAP_SERVICE, *PVMMDLL_MAP_SERVICE;
 
+typedef struct tdVMMDLL_MAP_KDRIVER {
+    DWORD dwVersion;
+    DWORD _Reserved1[5];
+    PBYTE pbMultiText;              // multi-wstr pointed into by VMMDLL_MAP_KDRIVERENTRY.wsz*
+    DWORD cbMultiText;
+    DWORD cMap;                     // # map entries.
+    VMMDLL_MAP_KDRIVERENTRY pMap[]; // map entries.
+} VMMDLL_MAP_KDRIVER, *PVMMDLL_MAP_KDRIVER;
+
 /*
 * Retrieve the memory map entries based on hardware page tables (PTEs) for the process.
 * Entries returned are sorted on VMMDLL_MAP_PTEENTRY.va
@@ -1750,6 +1773,17 @@ EXPORTED_FUNCTION
 _Success_(return) BOOL VMMDLL_Map_GetServicesU(_In_ VMM_HANDLE hVMM, _Out_ PVMMDLL_MAP_SERVICE *ppServiceMap);
 _Success_(return) BOOL VMMDLL_Map_GetServicesW(_In_ VMM_HANDLE hVMM, _Out_ PVMMDLL_MAP_SERVICE *ppServiceMap);
 
+/*
+* Retrieve the kernel driver objects.
+* CALLER FREE: VMMDLL_MemFree(*ppKDriverMap)
+* -- hVMM
+* -- ppKDriverMap = ptr to receive result on success. must be free'd with VMMDLL_MemFree().
+* -- return = success/fail.
+*/
+EXPORTED_FUNCTION
+_Success_(return) BOOL VMMDLL_Map_GetKDriverU(_In_ VMM_HANDLE hVMM, _Out_ PVMMDLL_MAP_KDRIVER *ppKDriverMap);
+_Success_(return) BOOL VMMDLL_Map_GetKDriverW(_In_ VMM_HANDLE hVMM, _Out_ PVMMDLL_MAP_KDRIVER *ppKDriverMap);
+
 
 
 //-----------------------------------------------------------------------------
@@ -2626,6 +2660,7 @@ BOOL VMMDLL_UtilFillHexAscii(
 #define VMMDLL_Map_GetNet               VMMDLL_Map_GetNetW
 #define VMMDLL_Map_GetUsers             VMMDLL_Map_GetUsersW
 #define VMMDLL_Map_GetServices          VMMDLL_Map_GetServicesW
+#define VMMDLL_Map_GetKDriver           VMMDLL_Map_GetKDriverW
 #define VMMDLL_WinGetThunkInfoIAT       VMMDLL_WinGetThunkInfoIATW
 #endif /* _WIN32 */
 
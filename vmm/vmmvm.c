@@ -1060,6 +1060,7 @@ BOOL VmmVm_TranslateGPA(_In_ VMM_HANDLE H, _In_ VMMVM_HANDLE HVM, _In_ ULONG64 q
     PVMMOB_VM_CONTEXT pObVM = NULL;
     if(pPA) { *pPA = 0; }
     if(pVA) { *pVA = 0; }
+    MEM.qwA = qwGPA;
     if(!H->fAbort && (pObVM = VmmVm_GetVmContext(H, HVM))) {
         AcquireSRWLockShared(&pObVM->LockSRW);
         if(pObVM->fActive) {
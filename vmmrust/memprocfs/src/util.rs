@@ -0,0 +1,284 @@
+//! Utility functions.
+//!
+//! Helpers for plugins generating virtual file contents in the same way as
+//! the native MemProcFS plugins (`Util_VfsReadFile_*`).
+
+use std::fmt;
+
+
+
+/// Plugin utility: read from a buffer.
+/// 
+/// Return the slice of `data` requested by a plugin read callback. Reads
+/// beyond the end of the buffer return an empty result (end of file).
+/// 
+/// # Arguments
+/// * `data` - File contents.
+/// * `cb` - Number of bytes to read.
+/// * `cb_offset` - Offset to read from.
+/// 
+/// # Examples
+/// ```
+/// return Ok(util::vfs_read_from_buffer(&data, cb, cb_offset));
+/// ```
+pub fn vfs_read_from_buffer(data : &[u8], cb : u32, cb_offset : u64) -> Vec<u8> {
+    return impl_read_from_buffer(data, cb, cb_offset);
+}
+
+/// Plugin utility: read from a string.
+/// 
+/// # Arguments
+/// * `text` - File contents.
+/// * `cb` - Number of bytes to read.
+/// * `cb_offset` - Offset to read from.
+/// 
+/// # Examples
+/// ```
+/// return Ok(util::vfs_read_from_string("hello world\n", cb, cb_offset));
+/// ```
+pub fn vfs_read_from_string(text : &str, cb : u32, cb_offset : u64) -> Vec<u8> {
+    return impl_read_from_buffer(text.as_bytes(), cb, cb_offset);
+}
+
+/// Plugin utility: read from a number formatted as decimal text.
+/// 
+/// # Arguments
+/// * `value` - Number.
+/// * `cb` - Number of bytes to read.
+/// * `cb_offset` - Offset to read from.
+/// 
+/// # Examples
+/// ```
+/// return Ok(util::vfs_read_from_number(process.pid as u64, cb, cb_offset));
+/// ```
+pub fn vfs_read_from_number(value : u64, cb : u32, cb_offset : u64) -> Vec<u8> {
+    return impl_read_from_buffer(value.to_string().as_bytes(), cb, cb_offset);
+}
+
+/// Plugin utility: read from a 64-bit value formatted as 16 hex digits.
+/// 
+/// # Arguments
+/// * `value` - Value.
+/// * `is_prefix` - Prefix the value with `0x`.
+/// * `cb` - Number of bytes to read.
+/// * `cb_offset` - Offset to read from.
+/// 
+/// # Examples
+/// ```
+/// return Ok(util::vfs_read_from_u64(info.va_eprocess, false, cb, cb_offset));
+/// ```
+pub fn vfs_read_from_u64(value : u64, is_prefix : bool, cb : u32, cb_offset : u64) -> Vec<u8> {
+    let text = if is_prefix { format!("0x{:016x}", value) } else { format!("{:016x}", value) };
+    return impl_read_from_buffer(text.as_bytes(), cb, cb_offset);
+}
+
+/// Plugin utility: read from a 32-bit value formatted as 8 hex digits.
+/// 
+/// # Arguments
+/// * `value` - Value.
+/// * `is_prefix` - Prefix the value with `0x`.
+/// * `cb` - Number of bytes to read.
+/// * `cb_offset` - Offset to read from.
+/// 
+/// # Examples
+/// ```
+/// return Ok(util::vfs_read_from_u32(info.state, false, cb, cb_offset));
+/// ```
+pub fn vfs_read_from_u32(value : u32, is_prefix : bool, cb : u32, cb_offset : u64) -> Vec<u8> {
+    let text = if is_prefix { format!("0x{:08x}", value) } else { format!("{:08x}", value) };
+    return impl_read_from_buffer(text.as_bytes(), cb, cb_offset);
+}
+
+/// Plugin utility: read from a boolean formatted as `1` or `0`.
+/// 
+/// # Arguments
+/// * `value` - Value.
+/// * `cb` - Number of bytes to read.
+/// * `cb_offset` - Offset to read from.
+/// 
+/// # Examples
+/// ```
+/// return Ok(util::vfs_read_from_bool(info.is_wow64, cb, cb_offset));
+/// ```
+pub fn vfs_read_from_bool(value : bool, cb : u32, cb_offset : u64) -> Vec<u8> {
+    return impl_read_from_buffer(if value { b"1" } else { b"0" }, cb, cb_offset);
+}
+
+
+
+/// Plugin Line Table: Fixed-width line based text file generation.
+/// 
+/// MemProcFS text files listing entries (such as `handles.txt`) consist of
+/// lines of equal length. Each row is prefixed by its hex index, truncated or
+/// space-padded to the line length and terminated by a newline. Fixed-width
+/// lines allow the file size to be calculated from the number of rows and
+/// allow reads at an offset to render the requested lines only.
+/// 
+/// Header lines are rendered before the rows in the same fixed-width format
+/// without the index prefix.
+/// 
+/// # Created By
+/// - `VmmPluginLineTable::new()`
+/// 
+/// # Examples
+/// ```
+/// let mut table = VmmPluginLineTable::new(64);
+/// table.add_header(&format!("{:>4} {:>8} {}", "#", "PID", "Name"));
+/// table.add_header(&"-".repeat(63));
+/// for process in vmm.process_list()? {
+///     table.add_row(&format!("{:>8} {}", process.pid, process.info()?.name));
+/// }
+/// // In the list callback:
+/// file_list.add_file("processes.txt", table.size());
+/// // In the read callback:
+/// return Ok(table.read(cb, cb_offset));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VmmPluginLineTable {
+    line_length : usize,
+    header      : Vec<String>,
+    rows        : Vec<String>,
+}
+
+impl VmmPluginLineTable {
+    /// Create a new line table.
+    /// 
+    /// # Arguments
+    /// * `line_length` - Length of each line in bytes including the terminating newline.
+    pub fn new(line_length : usize) -> Self {
+        return VmmPluginLineTable {
+            line_length : std::cmp::max(line_length, 2),
+            header : Vec::new(),
+            rows : Vec::new(),
+        };
+    }
+
+    /// Add a header line.
+    /// 
+    /// # Arguments
+    /// * `line` - Header line text.
+    pub fn add_header(&mut self, line : &str) {
+        self.header.push(line.to_string());
+    }
+
+    /// Add a row. The row is prefixed with its hex index.
+    /// 
+    /// # Arguments
+    /// * `line` - Row text.
+    pub fn add_row(&mut self, line : &str) {
+        self.rows.push(format!("{:04x} {}", self.rows.len(), line));
+    }
+
+    /// Retrieve the number of rows (excluding header lines).
+    pub fn len(&self) -> usize {
+        return self.rows.len();
+    }
+
+    /// Check whether the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        return self.rows.is_empty();
+    }
+
+    /// Retrieve the total file size in bytes.
+    pub fn size(&self) -> u64 {
+        return ((self.header.len() + self.rows.len()) * self.line_length) as u64;
+    }
+
+    /// Render the lines requested by a plugin read callback.
+    /// 
+    /// # Arguments
+    /// * `cb` - Number of bytes to read.
+    /// * `cb_offset` - Offset to read from.
+    pub fn read(&self, cb : u32, cb_offset : u64) -> Vec<u8> {
+        return self.impl_read(cb, cb_offset);
+    }
+}
+
+
+
+//=============================================================================
+// INTERNAL: UTIL:
+//=============================================================================
+
+fn impl_read_from_buffer(data : &[u8], cb : u32, cb_offset : u64) -> Vec<u8> {
+    let o = std::cmp::min(cb_offset, data.len() as u64) as usize;
+    let o_end = std::cmp::min(o.saturating_add(cb as usize), data.len());
+    return data[o..o_end].to_vec();
+}
+
+impl fmt::Display for VmmPluginLineTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VmmPluginLineTable:{}:{}", self.line_length, self.rows.len())
+    }
+}
+
+impl VmmPluginLineTable {
+    fn impl_read(&self, cb : u32, cb_offset : u64) -> Vec<u8> {
+        let cb_total = self.size();
+        if cb_offset >= cb_total {
+            return Vec::new();
+        }
+        let cb_offset_end = std::cmp::min(cb_offset + cb as u64, cb_total);
+        let line_first = (cb_offset / self.line_length as u64) as usize;
+        let line_last = ((cb_offset_end - 1) / self.line_length as u64) as usize;
+        let mut data = Vec::with_capacity((line_last + 1 - line_first) * self.line_length);
+        for i in line_first..=line_last {
+            let line = if i < self.header.len() { &self.header[i] } else { &self.rows[i - self.header.len()] };
+            let mut line_data : Vec<u8> = line.bytes().filter(|b| *b != b'\n').take(self.line_length - 1).collect();
+            line_data.resize(self.line_length - 1, b' ');
+            line_data.push(b'\n');
+            data.extend_from_slice(&line_data);
+        }
+        let o = (cb_offset - (line_first * self.line_length) as u64) as usize;
+        return data[o..(o + (cb_offset_end - cb_offset) as usize)].to_vec();
+    }
+}
+
+
+
+//=============================================================================
+// TESTS: UTIL:
+//=============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vfs_read_from_buffer_offsets() {
+        assert_eq!(vfs_read_from_buffer(b"hello world", 5, 0), b"hello");
+        assert_eq!(vfs_read_from_buffer(b"hello world", 0x100, 6), b"world");
+        assert!(vfs_read_from_buffer(b"hello world", 4, 11).is_empty());
+        assert!(vfs_read_from_buffer(b"hello world", 4, u64::MAX).is_empty());
+        assert_eq!(vfs_read_from_buffer(b"hello world", u32::MAX, 10), b"d");
+    }
+
+    #[test]
+    fn vfs_read_from_values() {
+        assert_eq!(vfs_read_from_string("hello", 3, 1), b"ell");
+        assert_eq!(vfs_read_from_number(1234, 0x10, 0), b"1234");
+        assert_eq!(vfs_read_from_u64(0x1234, false, 0x20, 0), b"0000000000001234");
+        assert_eq!(vfs_read_from_u64(0x1234, true, 0x20, 0), b"0x0000000000001234");
+        assert_eq!(vfs_read_from_u32(0xabc, false, 0x10, 0), b"00000abc");
+        assert_eq!(vfs_read_from_u32(0xabc, true, 0x10, 0), b"0x00000abc");
+        assert_eq!(vfs_read_from_bool(true, 1, 0), b"1");
+        assert_eq!(vfs_read_from_bool(false, 1, 0), b"0");
+    }
+
+    #[test]
+    fn line_table_render() {
+        let mut table = VmmPluginLineTable::new(8);
+        assert!(table.is_empty());
+        table.add_header("# Name\n");
+        table.add_row("a");
+        table.add_row("this row is too long");
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.size(), 24);
+        let data = table.read(0x100, 0);
+        assert_eq!(data, b"# Name \n0000 a \n0001 th\n");
+        assert_eq!(table.read(0x100, 0), table.read(table.size() as u32, 0));
+        assert_eq!(table.read(6, 10), b"00 a \n");
+        assert_eq!(table.read(4, 22), b"h\n");
+        assert!(table.read(4, 24).is_empty());
+    }
+}
@@ -379,7 +379,10 @@ pub fn main() {
         println!("========================================");
         println!("vmm.vfs_write():");
         let vfs_write_data = vec![1u8; 1];
-        vmm.vfs_write("/conf/config_process_show_terminated.txt", vfs_write_data, 0);
+        match vmm.vfs_write("/conf/config_process_show_terminated.txt", &vfs_write_data, 0) {
+            Ok(cb_written) => println!("vmm.vfs_write(): success [{} bytes]", cb_written),
+            Err(e) => println!("vmm.vfs_write(): fail [{}]", e),
+        }
 
 
         // Example: vmm.vfs_read():